@@ -0,0 +1,77 @@
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, State},
+    http::{header, request::Parts},
+    Json,
+};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{Error, Result};
+use crate::AppState;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessClaims {
+    pub sub: Uuid,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoginInput {
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    pub access_token: String,
+}
+
+pub async fn login(
+    State(state): State<AppState>,
+    Json(payload): Json<LoginInput>,
+) -> Result<Json<LoginResponse>> {
+    let now = Utc::now();
+    let claims = AccessClaims {
+        sub: payload.user_id,
+        iat: now.timestamp(),
+        exp: (now + Duration::minutes(state.config.jwt_maxage)).timestamp(),
+    };
+
+    let access_token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(state.config.jwt_secret.as_bytes()),
+    )
+    .map_err(|e| Error::Internal(e.to_string()))?;
+
+    Ok(Json(LoginResponse { access_token }))
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for AccessClaims {
+    type Rejection = Error;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self> {
+        let auth_header = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or_else(|| Error::Unauthorized("missing authorization header".to_string()))?;
+
+        let token = auth_header
+            .strip_prefix("Bearer ")
+            .ok_or_else(|| Error::Unauthorized("invalid authorization header".to_string()))?;
+
+        let token_data = decode::<AccessClaims>(
+            token,
+            &DecodingKey::from_secret(state.config.jwt_secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|_| Error::Unauthorized("invalid or expired token".to_string()))?;
+
+        Ok(token_data.claims)
+    }
+}
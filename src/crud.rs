@@ -0,0 +1,96 @@
+use axum::{
+    extract::{Path, Query, State},
+    routing::get,
+    Json, Router,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::auth::AccessClaims;
+use crate::error::Result;
+use crate::validation::ValidatedJson;
+use crate::AppState;
+
+#[derive(Serialize, Debug)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub page: i64,
+    pub page_size: i64,
+    pub total: i64,
+}
+
+/// Implementing this trait for an entity wires up the standard list/get/create/update/delete
+/// routes for it, with auth and validation applied the same way every resource needs them.
+#[axum::async_trait]
+pub trait Crud: Sized + Serialize + Send + Sync + 'static {
+    type CreatePayload: DeserializeOwned + Validate + Send + 'static;
+    type UpdatePayload: DeserializeOwned + Validate + Send + 'static;
+    type ListParams: DeserializeOwned + Send + 'static;
+
+    const TABLE: &'static str;
+
+    async fn list(pool: &PgPool, params: Self::ListParams) -> Result<Paginated<Self>>;
+    async fn get(pool: &PgPool, id: Uuid) -> Result<Self>;
+    async fn create(pool: &PgPool, payload: Self::CreatePayload) -> Result<Self>;
+    async fn update(pool: &PgPool, id: Uuid, payload: Self::UpdatePayload) -> Result<Self>;
+    async fn delete(pool: &PgPool, id: Uuid) -> Result<()>;
+
+    fn router() -> Router<AppState> {
+        let collection = format!("/{}", Self::TABLE);
+        let member = format!("/{}/:id", Self::TABLE);
+
+        Router::new()
+            .route(
+                &collection,
+                get(Self::list_handler).post(Self::create_handler),
+            )
+            .route(
+                &member,
+                get(Self::get_handler)
+                    .put(Self::update_handler)
+                    .delete(Self::delete_handler),
+            )
+    }
+
+    async fn list_handler(
+        State(state): State<AppState>,
+        Query(params): Query<Self::ListParams>,
+    ) -> Result<Json<Paginated<Self>>> {
+        Self::list(&state.db, params).await.map(Json)
+    }
+
+    async fn get_handler(
+        State(state): State<AppState>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<Self>> {
+        Self::get(&state.db, id).await.map(Json)
+    }
+
+    async fn create_handler(
+        State(state): State<AppState>,
+        _claims: AccessClaims,
+        ValidatedJson(payload): ValidatedJson<Self::CreatePayload>,
+    ) -> Result<Json<Self>> {
+        Self::create(&state.db, payload).await.map(Json)
+    }
+
+    async fn update_handler(
+        State(state): State<AppState>,
+        Path(id): Path<Uuid>,
+        _claims: AccessClaims,
+        ValidatedJson(payload): ValidatedJson<Self::UpdatePayload>,
+    ) -> Result<Json<Self>> {
+        Self::update(&state.db, id, payload).await.map(Json)
+    }
+
+    async fn delete_handler(
+        State(state): State<AppState>,
+        Path(id): Path<Uuid>,
+        _claims: AccessClaims,
+    ) -> Result<Json<()>> {
+        Self::delete(&state.db, id).await?;
+        Ok(Json(()))
+    }
+}
@@ -1,8 +1,45 @@
-use sqlx::PgPool;
+use sqlx::{postgres::PgPoolOptions, PgPool};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub jwt_secret: String,
+    pub jwt_maxage: i64,
+}
+
+impl Config {
+    pub fn init() -> Self {
+        let jwt_secret = std::env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+        let jwt_maxage = std::env::var("JWT_MAXAGE")
+            .expect("JWT_MAXAGE must be set")
+            .parse::<i64>()
+            .expect("JWT_MAXAGE must be an integer");
+
+        Self {
+            jwt_secret,
+            jwt_maxage,
+        }
+    }
+}
 
 pub async fn connect_db() -> Result<PgPool, sqlx::Error> {
     let database_url = std::env::var("DATABASE_URL")
         .expect("DATABASE_URL must be set");
 
-    PgPool::connect(&database_url).await
-}
\ No newline at end of file
+    let max_connections = std::env::var("DATABASE_MAX_CONNECTIONS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or_else(|| num_cpus::get() as u32);
+
+    let acquire_timeout_secs = std::env::var("DATABASE_ACQUIRE_TIMEOUT_SECS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(3);
+
+    PgPoolOptions::new()
+        .max_connections(max_connections)
+        .acquire_timeout(Duration::from_secs(acquire_timeout_secs))
+        .test_before_acquire(true)
+        .connect(&database_url)
+        .await
+}
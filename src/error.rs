@@ -0,0 +1,59 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0} not found")]
+    NotFound(&'static str),
+
+    #[error("database error")]
+    Database(#[from] sqlx::Error),
+
+    #[error("{0}")]
+    Validation(String),
+
+    #[error("unauthorized")]
+    Unauthorized(String),
+
+    #[error("internal error")]
+    Internal(String),
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let (status, message) = match &self {
+            Error::NotFound(what) => (StatusCode::NOT_FOUND, what.to_string()),
+            Error::Database(e) => {
+                tracing::error!("database error: {e}");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "an unexpected error occurred".to_string(),
+                )
+            }
+            Error::Validation(message) => (StatusCode::BAD_REQUEST, message.clone()),
+            Error::Unauthorized(message) => (StatusCode::UNAUTHORIZED, message.clone()),
+            Error::Internal(e) => {
+                tracing::error!("internal error: {e}");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "an unexpected error occurred".to_string(),
+                )
+            }
+        };
+
+        (
+            status,
+            Json(json!({
+                "status": "error",
+                "message": message,
+            })),
+        )
+            .into_response()
+    }
+}
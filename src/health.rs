@@ -0,0 +1,20 @@
+use axum::{extract::State, http::StatusCode, routing::get, Router};
+
+use crate::AppState;
+
+async fn liveness() -> StatusCode {
+    StatusCode::OK
+}
+
+async fn readiness(State(state): State<AppState>) -> StatusCode {
+    match sqlx::query("SELECT 1").execute(&state.db).await {
+        Ok(_) => StatusCode::OK,
+        Err(_) => StatusCode::SERVICE_UNAVAILABLE,
+    }
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(liveness))
+        .route("/postgres", get(readiness))
+}
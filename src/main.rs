@@ -1,16 +1,32 @@
+mod auth;
+mod crud;
 mod db;
+mod error;
+mod health;
+mod validation;
+
+use auth::login;
 use axum::{
-    routing::get,
-    Router, Json, extract::{Path, State}
+    routing::{get, post},
+    Router,
 };
+use crud::{Crud, Paginated};
+use error::{Error, Result};
 use tokio::net::TcpListener;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use std::net::SocketAddr;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
+use validator::Validate;
+
+#[derive(Clone)]
+pub(crate) struct AppState {
+    pub(crate) db: PgPool,
+    pub(crate) config: db::Config,
+}
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, sqlx::FromRow)]
 struct Todo {
     id: Uuid,
     title: String,
@@ -18,104 +34,141 @@ struct Todo {
     created_at: DateTime<Utc>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Validate)]
 struct CreateTodo {
+    #[validate(length(min = 1, max = 512))]
     title: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Validate)]
 struct UpdateTodo {
+    #[validate(length(min = 1, max = 512))]
     title: Option<String>,
     completed: Option<bool>,
 }
 
-async fn get_todos(State(pool): State<PgPool>) -> Result<Json<Vec<Todo>>, (axum::http::StatusCode, String)> {
-    let todos = sqlx::query_as!(
-        Todo,
-        "SELECT id, title, completed, created_at FROM todos",
-    )
-    .fetch_all(&pool)
-    .await
-    .map_err(|e| (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-
-    Ok(Json(todos))
+#[derive(Deserialize, Debug)]
+struct ListTodos {
+    page: Option<i64>,
+    page_size: Option<i64>,
+    completed: Option<bool>,
+    sort: Option<String>,
 }
 
-async fn create_todo(
-    State(pool): State<PgPool>,
-    Json(payload): Json<CreateTodo>,
-) -> Result<Json<Todo>, (axum::http::StatusCode, String)> {
-    let todo = sqlx::query_as!(
-        Todo,
-        "INSERT INTO todos (title) VALUES ($1) RETURNING id, title, completed, created_at",
-        payload.title
-    )
-    .fetch_one(&pool)
-    .await
-    .map_err(|e| (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-
-    Ok(Json(todo))
-}
+const SORTABLE_COLUMNS: &[&str] = &["id", "title", "completed", "created_at"];
 
-async fn get_todo(
-    State(pool): State<PgPool>,
-    Path(id): Path<Uuid>,
-) -> Result<Json<Todo>, (axum::http::StatusCode, String)> {
-    let todo = sqlx::query_as!(
-        Todo,
-        "SELECT id, title, completed, created_at FROM todos WHERE id = $1",
-        id
-    )
-    .fetch_optional(&pool)
-    .await
-    .map_err(|e| (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
-    .ok_or((axum::http::StatusCode::NOT_FOUND, "Todo not found".to_string()))?;
-
-    Ok(Json(todo))
-}
+fn parse_sort(sort: Option<&str>) -> (&'static str, &'static str) {
+    let (column, direction) = match sort {
+        None => ("created_at", "desc"),
+        Some(s) => s.split_once('.').unwrap_or((s, "asc")),
+    };
+
+    let column = SORTABLE_COLUMNS
+        .iter()
+        .find(|c| **c == column)
+        .copied()
+        .unwrap_or("created_at");
+    let direction = if direction.eq_ignore_ascii_case("asc") { "asc" } else { "desc" };
 
-async fn update_todo(
-    State(pool): State<PgPool>,
-    Path(id): Path<Uuid>,
-    Json(payload): Json<UpdateTodo>
-) -> Result<Json<Todo>, (axum::http::StatusCode, String)> {
-    let todo = sqlx::query_as!(
-        Todo,
-        r#"
-        UPDATE todos
-        SET title = COALESCE($1, title), completed = COALESCE($2, completed)
-        WHERE id = $3
-        RETURNING id, title, completed, created_at
-        "#,
-        payload.title,
-        payload.completed,
-        id
-    )
-    .fetch_optional(&pool)
-    .await
-    .map_err(|e| (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
-    .ok_or((axum::http::StatusCode::NOT_FOUND, "Todo not found".to_string()))?;
-
-    Ok(Json(todo))
+    (column, direction)
 }
 
-async fn delete_todo(
-    State(pool): State<PgPool>,
-    Path(id): Path<Uuid>
-) -> Result<Json<()>, (axum::http::StatusCode, String)> {
-    let result = sqlx::query!(
-        "DELETE FROM todos WHERE id = $1",
-        id
-    )
-    .execute(&pool)
-    .await
-    .map_err(|e| (axum::http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
-
-    if result.rows_affected() == 0 {
-        return Err((axum::http::StatusCode::NOT_FOUND, "Todo not found".to_string()));
+#[axum::async_trait]
+impl Crud for Todo {
+    type CreatePayload = CreateTodo;
+    type UpdatePayload = UpdateTodo;
+    type ListParams = ListTodos;
+
+    const TABLE: &'static str = "todos";
+
+    async fn list(pool: &PgPool, params: Self::ListParams) -> Result<Paginated<Self>> {
+        const MAX_PAGE: i64 = i64::MAX / 100;
+
+        let page = params.page.unwrap_or(1).clamp(1, MAX_PAGE);
+        let page_size = params.page_size.unwrap_or(20).clamp(1, 100);
+        let offset = (page - 1) * page_size;
+        let (sort_column, sort_direction) = parse_sort(params.sort.as_deref());
+
+        let query = format!(
+            "SELECT id, title, completed, created_at FROM todos \
+             WHERE ($1::bool IS NULL OR completed = $1) \
+             ORDER BY {sort_column} {sort_direction} \
+             LIMIT $2 OFFSET $3"
+        );
+
+        let items = sqlx::query_as::<_, Todo>(&query)
+            .bind(params.completed)
+            .bind(page_size)
+            .bind(offset)
+            .fetch_all(pool)
+            .await?;
+
+        let total = sqlx::query_scalar!(
+            "SELECT count(*) FROM todos WHERE ($1::bool IS NULL OR completed = $1)",
+            params.completed
+        )
+        .fetch_one(pool)
+        .await?
+        .unwrap_or(0);
+
+        Ok(Paginated {
+            items,
+            page,
+            page_size,
+            total,
+        })
+    }
+
+    async fn get(pool: &PgPool, id: Uuid) -> Result<Self> {
+        sqlx::query_as!(
+            Todo,
+            "SELECT id, title, completed, created_at FROM todos WHERE id = $1",
+            id
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or(Error::NotFound("todo"))
+    }
+
+    async fn create(pool: &PgPool, payload: Self::CreatePayload) -> Result<Self> {
+        Ok(sqlx::query_as!(
+            Todo,
+            "INSERT INTO todos (title) VALUES ($1) RETURNING id, title, completed, created_at",
+            payload.title
+        )
+        .fetch_one(pool)
+        .await?)
     }
 
-    Ok(Json(()))
+    async fn update(pool: &PgPool, id: Uuid, payload: Self::UpdatePayload) -> Result<Self> {
+        sqlx::query_as!(
+            Todo,
+            r#"
+            UPDATE todos
+            SET title = COALESCE($1, title), completed = COALESCE($2, completed)
+            WHERE id = $3
+            RETURNING id, title, completed, created_at
+            "#,
+            payload.title,
+            payload.completed,
+            id
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or(Error::NotFound("todo"))
+    }
+
+    async fn delete(pool: &PgPool, id: Uuid) -> Result<()> {
+        let result = sqlx::query!("DELETE FROM todos WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::NotFound("todo"));
+        }
+
+        Ok(())
+    }
 }
 
 async fn hello() -> &'static str {
@@ -129,11 +182,20 @@ async fn main() {
 
     let pool = db::connect_db().await.expect("Failed to connect to DB");
 
+    sqlx::migrate!()
+        .run(&pool)
+        .await
+        .unwrap_or_else(|e| panic!("Failed to run database migrations: {e}"));
+
+    let config = db::Config::init();
+    let state = AppState { db: pool, config };
+
     let app = Router::new()
         .route("/", get(hello))
-        .route("/todos", get(get_todos).post(create_todo))
-        .route("/todos/:id", get(get_todo).put(update_todo).delete(delete_todo))
-        .with_state(pool);
+        .route("/auth/login", post(login))
+        .merge(Todo::router())
+        .nest("/hc", health::router())
+        .with_state(state);
 
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
     println!("ðŸš€ Listening on http://{}", addr);
@@ -142,4 +204,4 @@ async fn main() {
     axum::serve(listener, app.into_make_service())
         .await
         .unwrap();
-}
\ No newline at end of file
+}
@@ -0,0 +1,32 @@
+use axum::{
+    async_trait,
+    extract::{FromRequest, Request},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use validator::Validate;
+
+use crate::error::{Error, Result};
+
+pub struct ValidatedJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Error;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self> {
+        let Json(payload) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(|e| Error::Validation(e.to_string()))?;
+
+        payload
+            .validate()
+            .map_err(|e| Error::Validation(e.to_string()))?;
+
+        Ok(ValidatedJson(payload))
+    }
+}